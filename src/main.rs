@@ -1,11 +1,14 @@
 use bevy::input::ButtonState;
-use crate::GameState::{GameOver, InGame};
+use crate::GameState::{GameOver, InGame, Paused};
 use bevy::input::keyboard::KeyboardInput;
 use bevy::prelude::*;
-use bevy::sprite::Anchor;
+use bevy::time::Stopwatch;
 use bevy_prng::WyRand;
 use bevy_rand::prelude::{EntropyPlugin, GlobalEntropy};
+use bevy_rapier2d::prelude::*;
 use rand_core::RngCore;
+use std::collections::HashMap;
+use std::time::Duration;
 
 //region Constants
 const GAME_SPEED: f32 = 400.0;
@@ -17,10 +20,71 @@ const PLAYER_COLOR: Color = Color::srgb(0.5, 1.0, 0.5);
 const SPAWN_INTERVAL: f32 = 1.0;
 const GROUND_LEVEL: f32 = -100.0;
 const GROUND_SIZE: Vec2 = Vec2::new(800.0, 10.0);
-const GROUND_EDGE: f32 = GROUND_SIZE.x / 2.0;
 const GROUND_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
 const OBSTACLE_SIZE: Vec2 = Vec2::new(30.0, 30.0);
 const OBSTACLE_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
+// Difficulty ramp: over RAMP_SECS, obstacle speed and spawn rate scale up to their max.
+const RAMP_SECS: f32 = 60.0;
+const EXTRA_SPEED: f32 = 300.0;
+const MIN_INTERVAL: f32 = 0.4;
+const CONFIG_PATH: &str = "assets/config.ron";
+const HIGH_SCORE_PATH: &str = "score.dat";
+//endregion
+
+//region Config
+/// Tunable gameplay balance, loaded from `CONFIG_PATH` so it can be edited without a rebuild.
+#[derive(Resource, serde::Deserialize)]
+struct GameConfig {
+    game_speed: f32,
+    jump_force: f32,
+    gravity: f32,
+    spawn_interval: f32,
+    player_size: Vec2,
+    player_color: Color,
+    ground_size: Vec2,
+    ground_color: Color,
+    obstacle_size: Vec2,
+    obstacle_color: Color,
+    initial_health: usize,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            game_speed: GAME_SPEED,
+            jump_force: JUMP_FORCE,
+            gravity: GRAVITY,
+            spawn_interval: SPAWN_INTERVAL,
+            player_size: PLAYER_SIZE,
+            player_color: PLAYER_COLOR,
+            ground_size: GROUND_SIZE,
+            ground_color: GROUND_COLOR,
+            obstacle_size: OBSTACLE_SIZE,
+            obstacle_color: OBSTACLE_COLOR,
+            initial_health: 3,
+        }
+    }
+}
+
+fn load_game_config() -> GameConfig {
+    std::fs::read_to_string(CONFIG_PATH)
+        .ok()
+        .and_then(|contents| ron::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn load_high_score() -> HighScore {
+    std::fs::read_to_string(HIGH_SCORE_PATH)
+        .ok()
+        .and_then(|contents| ron::from_str(&contents).ok())
+        .unwrap_or(HighScore(0))
+}
+
+fn save_high_score(high_score: &HighScore) {
+    if let Ok(serialized) = ron::to_string(high_score) {
+        let _ = std::fs::write(HIGH_SCORE_PATH, serialized);
+    }
+}
 //endregion
 
 //region Components, resources, and states
@@ -28,14 +92,25 @@ const OBSTACLE_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
 struct Player;
 
 #[derive(Component)]
-struct Velocity(Vec3);
+struct Obstacle;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum ObstacleKind {
+    // Spawns on or up to 49 units above the ground; a standing player runs into it.
+    Ground,
+    // Hangs at head height with a gap beneath; a standing player runs into it, a crouching one passes under.
+    Overhead,
+}
 
 #[derive(Component)]
-struct Obstacle;
+struct Scored;
 
 #[derive(Component)]
 struct GameOverText;
 
+#[derive(Component)]
+struct PausedText;
+
 #[derive(Component)]
 struct Health(usize);
 
@@ -48,120 +123,254 @@ struct OriginalSize(Vec2);
 #[derive(Resource)]
 struct ObstacleSpawningTimer(Timer);
 
+#[derive(Resource)]
+struct GameTimer(Stopwatch);
+
+#[derive(Resource)]
+struct CurrentSpeed(f32);
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource, serde::Serialize, serde::Deserialize)]
+struct HighScore(u32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum GameAction {
+    Jump,
+    Crouch,
+    Restart,
+    Pause,
+}
+
+#[derive(Resource)]
+struct InputMap(HashMap<GameAction, Vec<KeyCode>>);
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self(HashMap::from([
+            (GameAction::Jump, vec![KeyCode::Space]),
+            (GameAction::Crouch, vec![KeyCode::ArrowDown]),
+            (GameAction::Restart, vec![KeyCode::Space]),
+            (GameAction::Pause, vec![KeyCode::Escape]),
+        ]))
+    }
+}
+
+#[derive(Event)]
+struct ActionEvent {
+    action: GameAction,
+    state: ButtonState,
+}
+
+#[derive(Resource)]
+struct AudioAssets {
+    jump: Handle<AudioSource>,
+    hit: Handle<AudioSource>,
+    game_over: Handle<AudioSource>,
+}
+
+#[derive(Event)]
+enum GameAudioEvent {
+    Jump,
+    Hit,
+    GameOver,
+}
+
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
 enum GameState {
     InGame,
     GameOver,
+    Paused,
 }
 //endregion
 
 fn main() {
+    let config = load_game_config();
+    let initial_speed = config.game_speed;
+    let spawn_interval = config.spawn_interval;
+    let gravity = config.gravity;
+
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EntropyPlugin::<WyRand>::default())
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
         .add_systems(Startup, setup)
         .insert_resource(ObstacleSpawningTimer(
-            Timer::from_seconds(SPAWN_INTERVAL, TimerMode::Repeating)))
+            Timer::from_seconds(spawn_interval, TimerMode::Repeating)))
+        .insert_resource(GameTimer(Stopwatch::new()))
+        .insert_resource(CurrentSpeed(initial_speed))
+        .insert_resource(config)
+        .insert_resource(Score::default())
+        .insert_resource(load_high_score())
+        .insert_resource(InputMap::default())
+        .insert_resource(RapierConfiguration {
+            gravity: Vec2::new(0.0, gravity),
+            ..RapierConfiguration::new(1.0)
+        })
+        .add_event::<ActionEvent>()
+        .add_event::<GameAudioEvent>()
         .insert_state(InGame)
-        .add_systems(Update, (jump, apply_gravity, player_movement, crouch)
+        .add_systems(Update, (dispatch_input, play_audio))
+        .add_systems(Update, toggle_pause.run_if(not(in_state(GameOver))))
+        .add_systems(Update, (jump, crouch)
             .run_if(in_state(InGame)))
-        .add_systems(Update, (spawn_obstacles, move_obstacles, detect_collision, render_health_info, check_health)
+        .add_systems(Update, (spawn_obstacles, update_difficulty, move_obstacles, detect_collision, render_health_info, check_health)
+            .chain()
             .run_if(in_state(InGame)))
         .add_systems(OnEnter(GameOver), game_over)
         .add_systems(Update, restart_game.run_if(in_state(GameOver))) // New system to restart the game
+        .add_systems(OnEnter(Paused), pause_overlay)
+        .add_systems(OnExit(Paused), despawn_pause_overlay)
         .run();
 }
 
-fn setup(mut commands: Commands) {
+fn setup(mut commands: Commands, config: Res<GameConfig>, high_score: Res<HighScore>, asset_server: Res<AssetServer>) {
     commands.spawn(Camera2d::default());
 
-    let initial_health = 3;
+    commands.insert_resource(AudioAssets {
+        jump: asset_server.load("sounds/jump.ogg"),
+        hit: asset_server.load("sounds/hit.ogg"),
+        game_over: asset_server.load("sounds/game_over.ogg"),
+    });
+
+    let initial_health = config.initial_health;
     // Player
     commands
         .spawn((
             Player,
             Sprite {
-                color: PLAYER_COLOR,
-                custom_size: Some(PLAYER_SIZE),
-                anchor: Anchor::BottomCenter,
+                color: config.player_color,
+                custom_size: Some(config.player_size),
                 ..default()
             },
-            Transform::from_xyz(PLAYER_X, GROUND_LEVEL, 0.0),
-            Velocity(Vec3::ZERO),
+            Transform::from_xyz(PLAYER_X, GROUND_LEVEL + config.player_size.y / 2.0, 0.0),
             Health(initial_health),
-            OriginalSize(PLAYER_SIZE),
+            OriginalSize(config.player_size),
+            RigidBody::Dynamic,
+            Collider::cuboid(config.player_size.x / 2.0, config.player_size.y / 2.0),
+            LockedAxes::ROTATION_LOCKED,
+            Velocity::zero(),
+            ActiveEvents::COLLISION_EVENTS,
         ));
 
     commands.spawn((
         HealthInfo,
-        Text::new(format!("Health: {}", initial_health))
+        Text::new(format!("Health: {}\nScore: 0\nHigh Score: {}", initial_health, high_score.0))
     )
     );
 
     // Ground
     commands.spawn((
         Sprite {
-            color: GROUND_COLOR,
-            custom_size: Some(GROUND_SIZE),
-            anchor: Anchor::TopLeft,
+            color: config.ground_color,
+            custom_size: Some(config.ground_size),
             ..default()
         },
-        Transform::from_xyz(-GROUND_EDGE, GROUND_LEVEL, 0.0)
+        Transform::from_xyz(0.0, GROUND_LEVEL - config.ground_size.y / 2.0, 0.0),
+        RigidBody::Fixed,
+        Collider::cuboid(config.ground_size.x / 2.0, config.ground_size.y / 2.0),
     ));
 }
 
-fn jump(
+// Reads raw key events and emits the typed ActionEvents the gameplay systems consume.
+fn dispatch_input(
     mut events: EventReader<KeyboardInput>,
-    mut query: Query<(&mut Velocity, &Transform), With<Player>>
+    input_map: Res<InputMap>,
+    mut action_events: EventWriter<ActionEvent>,
 ) {
     for e in events.read() {
-        if let Ok((mut velocity, transform)) = query.get_single_mut() {
-            if e.state.is_pressed() && e.key_code == KeyCode::Space && transform.translation.y <= GROUND_LEVEL {
-                velocity.0.y = JUMP_FORCE;
+        for (&action, keys) in input_map.0.iter() {
+            // Held-key auto-repeats must not re-toggle pause
+            if action == GameAction::Pause && e.repeat {
+                continue;
+            }
+            if keys.contains(&e.key_code) {
+                action_events.send(ActionEvent { action, state: e.state });
             }
         }
     }
 }
 
-fn player_movement(
-    time: Res<Time>,
-    mut query: Query<(&mut Transform, &mut Velocity), With<Player>>
+// Toggles InGame <-> Paused on a Pause key-press edge; does nothing during GameOver.
+fn toggle_pause(
+    mut action_events: EventReader<ActionEvent>,
+    current_state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
-    for (mut transform, mut velocity) in query.iter_mut() {
-        transform.translation.y += velocity.0.y * time.delta_secs();
+    for e in action_events.read() {
+        if e.action == GameAction::Pause && e.state.is_pressed() {
+            match current_state.get() {
+                GameState::InGame => next_state.set(Paused),
+                GameState::Paused => next_state.set(InGame),
+                GameState::GameOver => {}
+            }
+        }
+    }
+}
 
-        if transform.translation.y <= GROUND_LEVEL {
-            transform.translation.y = GROUND_LEVEL;
-            velocity.0.y = 0.0;
+fn jump(
+    mut action_events: EventReader<ActionEvent>,
+    mut audio_events: EventWriter<GameAudioEvent>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Velocity, &Transform), With<Player>>
+) {
+    let ground_y = GROUND_LEVEL + config.player_size.y / 2.0;
+    for e in action_events.read() {
+        if let Ok((mut velocity, transform)) = query.get_single_mut() {
+            if e.action == GameAction::Jump && e.state.is_pressed() && transform.translation.y <= ground_y {
+                velocity.linvel.y = config.jump_force;
+                audio_events.send(GameAudioEvent::Jump);
+            }
         }
     }
 }
 
-fn apply_gravity(time: Res<Time>, mut query: Query<&mut Velocity, With<Player>>) {
-    for mut velocity in query.iter_mut() {
-        velocity.0.y += GRAVITY * time.delta_secs();
+fn play_audio(
+    mut commands: Commands,
+    mut audio_events: EventReader<GameAudioEvent>,
+    audio_assets: Res<AudioAssets>,
+) {
+    for event in audio_events.read() {
+        let clip = match event {
+            GameAudioEvent::Jump => audio_assets.jump.clone(),
+            GameAudioEvent::Hit => audio_assets.hit.clone(),
+            GameAudioEvent::GameOver => audio_assets.game_over.clone(),
+        };
+        commands.spawn((AudioPlayer(clip), PlaybackSettings::DESPAWN));
     }
 }
 
 fn spawn_obstacles(
     mut commands: Commands,
     time: Res<Time>,
+    config: Res<GameConfig>,
     mut spawn_timer: ResMut<ObstacleSpawningTimer>,
     mut rng: GlobalEntropy<WyRand>,
 ) {
     spawn_timer.0.tick(time.delta());
     if spawn_timer.0.finished() {
-        let obstacle_x = GROUND_EDGE;
-        let obstacle_y = GROUND_LEVEL + (rng.next_u32() % 50) as f32;
+        let kind = if rng.next_u32() % 2 == 0 { ObstacleKind::Ground } else { ObstacleKind::Overhead };
+        let obstacle_x = config.ground_size.x / 2.0;
+        let obstacle_y = match kind {
+            ObstacleKind::Ground => GROUND_LEVEL + (rng.next_u32() % 50) as f32 + config.obstacle_size.y / 2.0,
+            // Bottom edge sits well below standing head height so a standing player
+            // genuinely collides and a crouch (which halves the collider height) clears it.
+            ObstacleKind::Overhead => GROUND_LEVEL + config.player_size.y * 0.6 + config.obstacle_size.y / 2.0,
+        };
         commands.spawn((
             Obstacle,
+            kind,
             Sprite {
-                color: OBSTACLE_COLOR,
-                custom_size: Some(OBSTACLE_SIZE),
-                anchor: Anchor::BottomCenter,
+                color: config.obstacle_color,
+                custom_size: Some(config.obstacle_size),
                 ..default()
             },
             Transform::from_xyz(obstacle_x, obstacle_y, 0.0),
+            RigidBody::KinematicPositionBased,
+            Collider::cuboid(config.obstacle_size.x / 2.0, config.obstacle_size.y / 2.0),
+            Sensor,
+            ActiveEvents::COLLISION_EVENTS,
         ));
     }
 }
@@ -169,43 +378,71 @@ fn spawn_obstacles(
 fn move_obstacles(
     time: Res<Time>,
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform), With<Obstacle>>,
+    current_speed: Res<CurrentSpeed>,
+    config: Res<GameConfig>,
+    mut score: ResMut<Score>,
+    mut query: Query<(Entity, &mut Transform, Option<&Scored>), With<Obstacle>>,
 ) {
-    for (entity, mut transform) in query.iter_mut() {
-        transform.translation.x -= GAME_SPEED * time.delta_secs();
+    for (entity, mut transform, scored) in query.iter_mut() {
+        transform.translation.x -= current_speed.0 * time.delta_secs();
+
+        // Award a point the first frame the obstacle passes the player
+        if scored.is_none() && transform.translation.x <= PLAYER_X {
+            score.0 += 1;
+            commands.entity(entity).insert(Scored);
+        }
 
         // Remove obstacles once they're off-screen
-        if transform.translation.x < -GROUND_EDGE {
+        if transform.translation.x < -config.ground_size.x / 2.0 {
             commands.entity(entity).despawn();
         }
     }
 }
 
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+// Ramps obstacle speed and spawn rate up over RAMP_SECS of elapsed play time.
+fn update_difficulty(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut game_timer: ResMut<GameTimer>,
+    mut current_speed: ResMut<CurrentSpeed>,
+    mut spawn_timer: ResMut<ObstacleSpawningTimer>,
+) {
+    game_timer.0.tick(time.delta());
+    let d = (game_timer.0.elapsed_secs() / RAMP_SECS).min(1.0);
+
+    current_speed.0 = config.game_speed + d * EXTRA_SPEED;
+
+    if spawn_timer.0.finished() {
+        spawn_timer.0.set_duration(Duration::from_secs_f32(lerp(config.spawn_interval, MIN_INTERVAL, d)));
+    }
+}
+
+// Whether a crouched player actually clears an ObstacleKind::Overhead obstacle falls out of the
+// collider shapes themselves (crouch shrinks the player's Collider in `crouch`), so this just
+// reacts to whatever overlaps Rapier already reported.
 fn detect_collision(
     mut commands: Commands,
-    mut player_query: Query<(&Transform, &mut Health, &Sprite), With<Player>>,
-    obstacle_query: Query<(Entity, &Transform, &Sprite), With<Obstacle>>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut audio_events: EventWriter<GameAudioEvent>,
+    mut player_query: Query<&mut Health, With<Player>>,
+    obstacle_query: Query<Entity, With<Obstacle>>,
 ) {
-    if let Ok((player_transform, mut health, player_sprite)) = player_query.get_single_mut() {
-        let player_size = player_sprite.custom_size.unwrap_or(PLAYER_SIZE);
-        let player_half_width = player_size.x / 2.0;
-        let player_half_height = player_size.y / 2.0;
-
-        for (entity, obstacle_transform, obstacle_sprite) in obstacle_query.iter() {
-            let obstacle_size = obstacle_sprite.custom_size.unwrap_or(OBSTACLE_SIZE);
-            let obstacle_half_width = obstacle_size.x / 2.0;
-            let obstacle_half_height = obstacle_size.y / 2.0;
-
-            // Check for AABB collision
-            let collision_x = (player_transform.translation.x - obstacle_transform.translation.x).abs()
-                <= (player_half_width + obstacle_half_width);
-            let collision_y = (player_transform.translation.y - obstacle_transform.translation.y).abs()
-                <= (player_half_height + obstacle_half_height);
-
-            if collision_x && collision_y {
+    for event in collision_events.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let obstacle_entity = [*e1, *e2].into_iter().find(|e| obstacle_query.get(*e).is_ok());
+
+        if let Some(obstacle_entity) = obstacle_entity {
+            if let Ok(mut health) = player_query.get_single_mut() {
                 health.0 -= 1;
-                commands.entity(entity).despawn();
+                audio_events.send(GameAudioEvent::Hit);
             }
+            commands.entity(obstacle_entity).despawn();
         }
     }
 }
@@ -221,27 +458,44 @@ fn check_health(
 }
 
 fn crouch(
-    mut events: EventReader<KeyboardInput>,
-    mut player_query: Query<(&mut Sprite, &OriginalSize), With<Player>>,
+    mut action_events: EventReader<ActionEvent>,
+    mut player_query: Query<(&mut Sprite, &mut Collider, &OriginalSize), With<Player>>,
 ) {
-    for e in events.read() {
-        if let Ok((mut sprite, original_size)) = player_query.get_single_mut() {
-            if e.state.is_pressed() && e.key_code == KeyCode::ArrowDown {
+    for e in action_events.read() {
+        if e.action != GameAction::Crouch {
+            continue;
+        }
+        if let Ok((mut sprite, mut collider, original_size)) = player_query.get_single_mut() {
+            if e.state.is_pressed() {
                 // Reduce the player's height to half its original size
                 let new_height = original_size.0.y / 2.0;
                 if let Some(size) = sprite.custom_size {
                     if size.y > new_height {
                         sprite.custom_size = Some(Vec2::new(size.x, new_height));
+                        *collider = Collider::cuboid(original_size.0.x / 2.0, new_height / 2.0);
                     }
                 }
-            } else if e.state == ButtonState::Released && e.key_code == KeyCode::ArrowDown {
+            } else if e.state == ButtonState::Released {
                 sprite.custom_size = Some(original_size.0);
+                *collider = Collider::cuboid(original_size.0.x / 2.0, original_size.0.y / 2.0);
             }
         }
     }
 }
 
-fn game_over(mut commands: Commands) {
+fn game_over(
+    mut commands: Commands,
+    score: Res<Score>,
+    mut high_score: ResMut<HighScore>,
+    mut audio_events: EventWriter<GameAudioEvent>,
+) {
+    if score.0 > high_score.0 {
+        high_score.0 = score.0;
+        save_high_score(&high_score);
+    }
+
+    audio_events.send(GameAudioEvent::GameOver);
+
     commands.spawn((Node {
         position_type: PositionType::Absolute,
         left: Val::Percent(10.),
@@ -262,13 +516,52 @@ fn game_over(mut commands: Commands) {
         });
 }
 
+fn pause_overlay(mut commands: Commands, mut rapier_config: ResMut<RapierConfiguration>) {
+    rapier_config.physics_pipeline_active = false;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(10.),
+            right: Val::Percent(10.),
+            top: Val::Percent(15.),
+            bottom: Val::Percent(15.),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        PausedText,
+    ))
+        .with_children(|builder| {
+            builder.spawn((
+                Text("PAUSED".to_string()),
+                TextFont::from_font_size(160.0),
+                TextLayout::new_with_justify(JustifyText::Center).with_no_wrap(),
+                TextColor(Color::srgb(1.0, 1.0, 1.0)),
+            ));
+        });
+}
+
+fn despawn_pause_overlay(
+    mut commands: Commands,
+    mut rapier_config: ResMut<RapierConfiguration>,
+    query: Query<Entity, With<PausedText>>,
+) {
+    rapier_config.physics_pipeline_active = true;
+
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
 fn render_health_info(
     player_query: Query<&mut Health, With<Player>>,
+    score: Res<Score>,
+    high_score: Res<HighScore>,
     mut health_info_query: Query<&mut Text, With<HealthInfo>>,
 ) {
     if let Ok(mut health_info) = health_info_query.get_single_mut() {
         if let Ok(health) = player_query.get_single() {
-            health_info.0 = format!("Health: {}", health.0);
+            health_info.0 = format!("Health: {}\nScore: {}\nHigh Score: {}", health.0, score.0, high_score.0);
         }
     }
 }
@@ -276,26 +569,38 @@ fn render_health_info(
 // New system to restart the game
 fn restart_game(
     mut commands: Commands,
-    mut events: EventReader<KeyboardInput>,
+    mut action_events: EventReader<ActionEvent>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut game_timer: ResMut<GameTimer>,
+    mut current_speed: ResMut<CurrentSpeed>,
+    mut score: ResMut<Score>,
+    high_score: Res<HighScore>,
+    config: Res<GameConfig>,
     player_query: Query<Entity, With<Player>>,
     obstacle_query: Query<Entity, With<Obstacle>>,
     mut health_info_query: Query<&mut Text, With<HealthInfo>>,
     game_over_text_query: Query<Entity, With<GameOverText>>,
 ) {
-    for e in events.read() {
-        if e.state.is_pressed() && e.key_code == KeyCode::Space {
+    for e in action_events.read() {
+        if e.action == GameAction::Restart && e.state.is_pressed() {
             // Reset game state
             game_state.set(InGame);
 
+            // Reset the difficulty ramp
+            game_timer.0.reset();
+            current_speed.0 = config.game_speed;
+
+            // Reset score, but keep the persisted high score
+            score.0 = 0;
+
             // Reset player health
             if let Ok(player_entity) = player_query.get_single() {
-                commands.entity(player_entity).insert(Health(3));
+                commands.entity(player_entity).insert(Health(config.initial_health));
             }
 
             // Update health info text
             if let Ok(mut health_info) = health_info_query.get_single_mut() {
-                health_info.0 = "Health: 3".to_string();
+                health_info.0 = format!("Health: {}\nScore: 0\nHigh Score: {}", config.initial_health, high_score.0);
             }
 
             // Despawn all obstacles